@@ -2,32 +2,22 @@ use std::error::Error as STDError;
 use std::fmt;
 use std::result::Result as STDResult;
 
-pub type Result<T> = STDResult<T, Error>;
+pub type Result<T, SPIE, PINE> = STDResult<T, Error<SPIE, PINE>>;
 
 #[derive(Debug)]
-pub enum Error {
-    Lat,
-    Spi,
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<SPIE, PINE> {
+    Lat(PINE),
+    Spi(SPIE),
 }
 
-impl fmt::Display for Error {
+impl<SPIE: fmt::Debug, PINE: fmt::Debug> fmt::Display for Error<SPIE, PINE> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Error::Lat => write!(f, "Latch Write Error"),
-            Error::Spi => write!(f, "SPI Write Error"),
+        match self {
+            Error::Lat(e) => write!(f, "Latch Write Error: {:?}", e),
+            Error::Spi(e) => write!(f, "SPI Write Error: {:?}", e),
         }
     }
 }
 
-impl STDError for Error {
-    fn description(&self) -> &str {
-        match *self {
-            Error::Lat => "Error writing to latch.",
-            Error::Spi => "Error writing to SPI.",
-        }
-    }
-
-    fn cause(&self) -> Option<&dyn STDError> {
-        None
-    }
-}
+impl<SPIE: fmt::Debug, PINE: fmt::Debug> STDError for Error<SPIE, PINE> {}
@@ -1,27 +1,41 @@
 pub mod error;
 
-use bitvec::prelude::{bitvec, BitVec, Bits, LittleEndian};
-use embedded_hal::blocking::spi::Write;
-use embedded_hal::digital::v2::OutputPin;
+use bitvec::order::{Lsb0, Msb0};
+use bitvec::prelude::{bitvec, AsBits, BitVec};
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
 use error::{Error, Result};
 use std::marker::PhantomData;
 use typenum::{Unsigned, U12, U16, U24};
 
+/// Size in bits of the TLC59711 write-command header: a 6-bit command, 5 function-control
+/// bits, and three 7-bit global brightness-control (BC) values.
+const HEADER_BITS: usize = 32;
+
+/// The TLC59711 6-bit write command (`0b100101`).
+const WRITE_COMMAND: u8 = 0b100101;
+
+/// Backing storage for the header and shift register: MSB-first bits over `u8` bytes, so
+/// `as_slice()` hands back the wire bytes that go straight over SPI.
+type Register = BitVec<Msb0, u8>;
+
 pub struct TLC59xxx<SPI, LAT, WORD, CHANNELS> {
     spi: SPI,
     lat: LAT,
-    shift_register: BitVec,
+    /// Per-device 32-bit write-command header, empty for parts with no command word (TLC5947).
+    header: Register,
+    shift_register: Register,
     phantom: PhantomData<(WORD, CHANNELS)>,
 }
 
 pub type TLC5947<SPI, LAT> = TLC59xxx<SPI, LAT, U12, U24>;
 
-impl<SPI: Write<u8>, LAT: OutputPin> TLC5947<SPI, LAT> {
+impl<SPI: SpiDevice, LAT: OutputPin> TLC5947<SPI, LAT> {
     /// Returns TLC59xxx driver with 24 channels & 12-bit words
     ///
     /// # Arguments
     ///
-    /// * `spi` The embedded-hal spi device
+    /// * `spi` The embedded-hal `SpiDevice`, chip-select is managed by this bus abstraction
     /// * `lat` An embedded-hal pin device, this is toggled once data has finished being written to the register
     /// * `chain_size` The amount of devices chained together
     pub fn new(spi: SPI, lat: LAT, chain_size: usize) -> TLC5947<SPI, LAT> {
@@ -31,31 +45,104 @@ impl<SPI: Write<u8>, LAT: OutputPin> TLC5947<SPI, LAT> {
 
 pub type TLC59711<SPI, LAT> = TLC59xxx<SPI, LAT, U16, U12>;
 
-impl<SPI: Write<u8>, LAT: OutputPin> TLC59711<SPI, LAT> {
+impl<SPI: SpiDevice, LAT: OutputPin> TLC59711<SPI, LAT> {
     /// Returns TLC59xxx driver with 12 channels & 16-bit words
     ///
     /// # Arguments
     ///
-    /// * `spi` The embedded-hal spi device
+    /// * `spi` The embedded-hal `SpiDevice`, chip-select is managed by this bus abstraction
     /// * `lat` An embedded-hal pin device, this is toggled once data has finished being written to the register
     /// * `chain_size` The amount of devices chained together
     pub fn new(spi: SPI, lat: LAT, chain_size: usize) -> TLC59711<SPI, LAT> {
         Self::new_device(spi, lat, chain_size)
     }
+
+    /// Sets the function-control bits of the write command, applied to every chained device
+    ///
+    /// # Arguments
+    ///
+    /// * `blank` Blanks all outputs when set, overriding the grayscale data
+    /// * `dsprpt` Enables the auto display repeat mode
+    /// * `tmgrst` Enables the display timing reset mode
+    /// * `extgck` Selects the GS reference clock, `true` for `SCLK`, `false` for the internal oscillator
+    /// * `outtmg` Selects the GS update edge, `true` for the rising edge, `false` for the falling edge of `LAT`
+    pub fn set_function_control(
+        &mut self,
+        blank: bool,
+        dsprpt: bool,
+        tmgrst: bool,
+        extgck: bool,
+        outtmg: bool,
+    ) {
+        for device in 0..(self.header.len() / HEADER_BITS) {
+            let base = device * HEADER_BITS + 6;
+            self.header.set(base, outtmg);
+            self.header.set(base + 1, extgck);
+            self.header.set(base + 2, tmgrst);
+            self.header.set(base + 3, dsprpt);
+            self.header.set(base + 4, blank);
+        }
+    }
+
+    /// Sets the global brightness-control (BC) values of the write command, applied to every
+    /// chained device
+    ///
+    /// # Arguments
+    ///
+    /// * `r` The red BC value, 0-127
+    /// * `g` The green BC value, 0-127
+    /// * `b` The blue BC value, 0-127
+    ///
+    /// The three fields are packed into the write command in blue, green, red order, per the
+    /// TLC59711 Function Control Data word layout.
+    pub fn set_brightness(&mut self, r: u8, g: u8, b: u8) {
+        assert!(r < 128 && g < 128 && b < 128);
+
+        for device in 0..(self.header.len() / HEADER_BITS) {
+            let base = device * HEADER_BITS + 11;
+            Self::set_bc_field(&mut self.header, base, b);
+            Self::set_bc_field(&mut self.header, base + 7, g);
+            Self::set_bc_field(&mut self.header, base + 14, r);
+        }
+    }
+
+    fn set_bc_field(header: &mut Register, start: usize, val: u8) {
+        for i in 0..7 {
+            header.set(start + i, (val >> (6 - i)) & 1 == 1);
+        }
+    }
 }
 
 impl<SPI, LAT, WORD, CHANNELS> TLC59xxx<SPI, LAT, WORD, CHANNELS>
 where
-    SPI: Write<u8>,
+    SPI: SpiDevice,
     LAT: OutputPin,
     WORD: Unsigned,
     CHANNELS: Unsigned,
 {
+    /// Bits of per-device header needed for this part, 0 for headerless parts like the TLC5947.
+    fn header_len(chain_size: usize) -> usize {
+        if WORD::to_usize() == U16::to_usize() {
+            HEADER_BITS * chain_size
+        } else {
+            0
+        }
+    }
+
     fn new_device(spi: SPI, lat: LAT, chain_size: usize) -> TLC59xxx<SPI, LAT, WORD, CHANNELS> {
+        let mut header = bitvec![Msb0, u8; 0; Self::header_len(chain_size)];
+        for device in 0..(header.len() / HEADER_BITS) {
+            let base = device * HEADER_BITS;
+            for i in 0..6 {
+                header.set(base + i, (WRITE_COMMAND >> (5 - i)) & 1 == 1);
+            }
+        }
+
         TLC59xxx {
             spi,
             lat,
-            shift_register: bitvec![0; CHANNELS::to_usize() * chain_size * WORD::to_usize()],
+            header,
+            shift_register: bitvec![Msb0, u8; 0; CHANNELS::to_usize() * chain_size * WORD::to_usize()],
             phantom: PhantomData,
         }
     }
@@ -82,10 +169,10 @@ where
 
         let end = self.shift_register.len() - channel * WORD::to_usize();
         let start = end - WORD::to_usize();
-        let mut new_val = val.as_bitslice::<LittleEndian>()[..WORD::to_usize()].iter();
+        let mut new_val = val.bits::<Lsb0>()[..WORD::to_usize()].iter();
 
         for x in start..end {
-            self.shift_register.set(x, new_val.next().unwrap());
+            self.shift_register.set(x, *new_val.next().unwrap());
         }
     }
 
@@ -111,14 +198,73 @@ where
         self.set_pwm(light + 2, rgb.2);
     }
 
+    /// Sets every channel across all chained devices in one pass
+    ///
+    /// # Arguments
+    ///
+    /// * `values` One value per channel, in channel order. Must match `channel_count()`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Embedded-hal device setup
+    /// let tlc = TLC5947::new(spi, lat, 2);
+    /// tlc.set_all(&[4095; 48]); //Set every channel on both devices to max
+    /// tlc.write();
+    /// ```
+    pub fn set_all(&mut self, values: &[u16]) {
+        assert_eq!(values.len(), self.channel_count());
+
+        let word = WORD::to_usize();
+        let mut shift_register = Register::with_capacity(self.shift_register.len());
+        for &val in values.iter().rev() {
+            assert!((val as usize) < 2usize.pow(WORD::to_u32()));
+            shift_register.extend(val.bits::<Lsb0>()[..word].iter().copied());
+        }
+        self.shift_register = shift_register;
+    }
+
+    /// Sets every rgb light across all chained devices in one pass, see `set_rgb`
+    ///
+    /// # Arguments
+    ///
+    /// * `values` One rgb triplet per light, in light order. Must match `channel_count() / 3`
+    pub fn set_all_rgb(&mut self, values: &[(u16, u16, u16)]) {
+        assert_eq!(values.len() * 3, self.channel_count());
+
+        let word = WORD::to_usize();
+        let mut shift_register = Register::with_capacity(self.shift_register.len());
+        for &(r, g, b) in values.iter().rev() {
+            for val in [b, g, r] {
+                assert!((val as usize) < 2usize.pow(WORD::to_u32()));
+                shift_register.extend(val.bits::<Lsb0>()[..word].iter().copied());
+            }
+        }
+        self.shift_register = shift_register;
+    }
+
+    /// Returns the total amount of channels across all chained devices
+    pub fn channel_count(&self) -> usize {
+        self.shift_register.len() / WORD::to_usize()
+    }
+
+    /// Returns the amount of chained devices
+    pub fn device_count(&self) -> usize {
+        self.channel_count() / CHANNELS::to_usize()
+    }
+
     /// Writes current register to the device
-    pub fn write(&mut self) -> Result<()> {
-        self.spi
-            .write(&self.shift_register.as_slice())
-            .map_err(|_| Error::Spi)?;
+    pub fn write(&mut self) -> Result<(), SPI::Error, LAT::Error> {
+        match self.build_frame() {
+            Some(frame) => self.spi.write(frame.as_slice()).map_err(Error::Spi)?,
+            None => self
+                .spi
+                .write(self.shift_register.as_slice())
+                .map_err(Error::Spi)?,
+        };
 
-        self.lat.set_high().map_err(|_| Error::Lat)?;
-        self.lat.set_low().map_err(|_| Error::Lat)?;
+        self.lat.set_high().map_err(Error::Lat)?;
+        self.lat.set_low().map_err(Error::Lat)?;
         Ok(())
     }
 
@@ -128,13 +274,80 @@ where
     }
 }
 
+impl<SPI, LAT, WORD, CHANNELS> TLC59xxx<SPI, LAT, WORD, CHANNELS>
+where
+    WORD: Unsigned,
+    CHANNELS: Unsigned,
+{
+    /// Builds the full per-device header + grayscale frame for parts with a write-command header
+    /// (TLC59711). Returns `None` for headerless parts (TLC5947), where `shift_register` is
+    /// already the whole frame. Kept in its own impl block (no SPI/LAT bounds) so both the
+    /// synchronous and async write paths can call it.
+    fn build_frame(&self) -> Option<Register> {
+        if self.header.is_empty() {
+            return None;
+        }
+
+        let device_bits = WORD::to_usize() * CHANNELS::to_usize();
+        let device_count = self.header.len() / HEADER_BITS;
+        let mut frame = Register::with_capacity(self.header.len() + self.shift_register.len());
+
+        for device in (0..device_count).rev() {
+            let header_start = device * HEADER_BITS;
+            frame.extend(
+                self.header[header_start..header_start + HEADER_BITS]
+                    .iter()
+                    .copied(),
+            );
+
+            let data_start = (device_count - device - 1) * device_bits;
+            frame.extend(
+                self.shift_register[data_start..data_start + device_bits]
+                    .iter()
+                    .copied(),
+            );
+        }
+
+        Some(frame)
+    }
+}
+
+/// Async write path, enabled with the `async` feature. Uses `embedded-hal-async`'s `SpiDevice` so
+/// a chain can be streamed out over DMA-backed SPI without blocking the executor, while `LAT` is
+/// still toggled through the regular (synchronous) embedded-hal `OutputPin`.
+#[cfg(feature = "async")]
+impl<SPI, LAT, WORD, CHANNELS> TLC59xxx<SPI, LAT, WORD, CHANNELS>
+where
+    SPI: embedded_hal_async::spi::SpiDevice,
+    LAT: OutputPin,
+    WORD: Unsigned,
+    CHANNELS: Unsigned,
+{
+    /// Writes current register to the device without blocking the executor
+    pub async fn write_async(&mut self) -> Result<(), SPI::Error, LAT::Error> {
+        match self.build_frame() {
+            Some(frame) => self.spi.write(frame.as_slice()).await.map_err(Error::Spi)?,
+            None => self
+                .spi
+                .write(self.shift_register.as_slice())
+                .await
+                .map_err(Error::Spi)?,
+        };
+
+        self.lat.set_high().map_err(Error::Lat)?;
+        self.lat.set_low().map_err(Error::Lat)?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bitvec::prelude::BitStore;
-    use embedded_hal_mock::{
-        pin::{Mock as PinMock, State as PinState, Transaction as PinTransaction},
+    use embedded_hal::spi::ErrorKind;
+    use embedded_hal_mock::eh1::{
+        digital::{Mock as PinMock, State as PinState, Transaction as PinTransaction},
         spi::{Mock as SpiMock, Transaction as SpiTransaction},
+        MockError,
     };
     use rand::{
         distributions::{Distribution, Uniform},
@@ -143,7 +356,7 @@ mod tests {
 
     fn test_configuration_random<WORD, CHANNELS>(
         chain_size: usize,
-    ) -> (TLC59xxx<SpiMock, PinMock, WORD, CHANNELS>, BitVec)
+    ) -> (TLC59xxx<SpiMock<u8>, PinMock, WORD, CHANNELS>, Register)
     where
         WORD: Unsigned,
         CHANNELS: Unsigned,
@@ -152,16 +365,46 @@ mod tests {
         let mut rng = thread_rng();
 
         let mut shift_register =
-            BitVec::with_capacity(WORD::to_usize() * CHANNELS::to_usize() * chain_size);
+            Register::with_capacity(WORD::to_usize() * CHANNELS::to_usize() * chain_size);
 
         for _ in 0..CHANNELS::to_usize() * chain_size {
             let random = uniform.sample(&mut rng);
-            for x in 0..WORD::to_u8() {
-                shift_register.push(random.get_at(x.into()));
+            for x in 0..WORD::to_usize() {
+                shift_register.push(*random.bits::<Lsb0>().get(WORD::to_usize() - 1 - x).unwrap());
             }
         }
-        let shift_register_rev: BitVec = shift_register.clone().into_iter().rev().collect();
-        let spi_expectation = [SpiTransaction::write(shift_register_rev.into_vec())];
+        let shift_register_rev: Register = shift_register.clone().into_iter().rev().collect();
+
+        let header_len = if WORD::to_usize() == 16 {
+            HEADER_BITS * chain_size
+        } else {
+            0
+        };
+        let mut header = bitvec![Msb0, u8; 0; header_len];
+        for device in 0..(header_len / HEADER_BITS) {
+            let base = device * HEADER_BITS;
+            for i in 0..6 {
+                header.set(base + i, (WRITE_COMMAND >> (5 - i)) & 1 == 1);
+            }
+        }
+
+        let frame = if header_len == 0 {
+            shift_register_rev.clone()
+        } else {
+            let device_bits = WORD::to_usize() * CHANNELS::to_usize();
+            let mut frame = Register::with_capacity(header_len + shift_register_rev.len());
+            for (device, chunk) in shift_register_rev.chunks(device_bits).enumerate() {
+                let base = device * HEADER_BITS;
+                frame.extend(header[base..base + HEADER_BITS].iter().copied());
+                frame.extend(chunk.iter().copied());
+            }
+            frame
+        };
+        let spi_expectation = [
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(frame.into_vec()),
+            SpiTransaction::transaction_end(),
+        ];
 
         let pin_expectation: [PinTransaction; 2] = [
             PinTransaction::set(PinState::High),
@@ -173,7 +416,8 @@ mod tests {
         let tlc = TLC59xxx {
             spi,
             lat,
-            shift_register: bitvec![0; WORD::to_usize() * CHANNELS::to_usize() * chain_size],
+            header,
+            shift_register: bitvec![Msb0, u8; 0; WORD::to_usize() * CHANNELS::to_usize() * chain_size],
             phantom: PhantomData,
         };
 
@@ -181,46 +425,103 @@ mod tests {
     }
 
     #[test]
-    fn write_47() -> Result<()> {
-        let (mut tlc, array): (TLC5947<_, _>, BitVec) = test_configuration_random(1);
+    fn write_47() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC5947<_, _>, Register) = test_configuration_random(1);
 
         for (pos, val) in array.chunks(12).enumerate() {
-            tlc.set_pwm(pos, val.iter().fold(0, |acc, bit| (acc << 1) | bit as u16));
+            tlc.set_pwm(pos, val.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16));
         }
         tlc.write()?;
 
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
         Ok(())
     }
 
     #[test]
-    fn rgb_47() -> Result<()> {
-        let (mut tlc, array): (TLC5947<_, _>, BitVec) = test_configuration_random(1);
+    fn rgb_47() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC5947<_, _>, Register) = test_configuration_random(1);
 
         for (pos, val) in array.chunks(36).enumerate() {
             let val: Vec<u16> = val
                 .chunks(12)
-                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | bit as u16))
+                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
                 .collect();
             tlc.set_rgb(pos, (val[0], val[1], val[2]));
         }
         tlc.write()?;
 
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_all_47() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC5947<_, _>, Register) = test_configuration_random(1);
+
+        assert_eq!(tlc.channel_count(), 24);
+        assert_eq!(tlc.device_count(), 1);
+
+        let values: Vec<u16> = array
+            .chunks(12)
+            .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
+            .collect();
+        tlc.set_all(&values);
+        tlc.write()?;
+
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_all_rgb_47() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC5947<_, _>, Register) = test_configuration_random(1);
+
+        let values: Vec<(u16, u16, u16)> = array
+            .chunks(36)
+            .map(|light| {
+                let channels: Vec<u16> = light
+                    .chunks(12)
+                    .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
+                    .collect();
+                (channels[0], channels[1], channels[2])
+            })
+            .collect();
+        tlc.set_all_rgb(&values);
+        tlc.write()?;
+
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
         Ok(())
     }
 
     #[test]
-    fn chained_47_512() -> Result<()> {
-        let (mut tlc, array): (TLC5947<_, _>, BitVec) = test_configuration_random(512);
+    fn chained_47_512() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC5947<_, _>, Register) = test_configuration_random(512);
 
         for (pos, val) in array.chunks(36).enumerate() {
             let val: Vec<u16> = val
                 .chunks(12)
-                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | bit as u16))
+                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
                 .collect();
             tlc.set_rgb(pos, (val[0], val[1], val[2]));
         }
         tlc.write()?;
 
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
         Ok(())
     }
 
@@ -228,7 +529,11 @@ mod tests {
     #[test]
     fn pwm_oor_47() {
         let array = vec![0; (12 * 24) / 8];
-        let spi_expectation = [SpiTransaction::write(array)];
+        let spi_expectation = [
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(array),
+            SpiTransaction::transaction_end(),
+        ];
 
         let pin_expectation: [PinTransaction; 2] = [
             PinTransaction::set(PinState::High),
@@ -243,46 +548,132 @@ mod tests {
     }
 
     #[test]
-    fn write_711() -> Result<()> {
-        let (mut tlc, array): (TLC59711<_, _>, BitVec) = test_configuration_random(1);
+    fn write_711() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC59711<_, _>, Register) = test_configuration_random(1);
 
         for (pos, val) in array.chunks(16).enumerate() {
-            tlc.set_pwm(pos, val.iter().fold(0, |acc, bit| (acc << 1) | bit as u16));
+            tlc.set_pwm(pos, val.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16));
         }
         tlc.write()?;
 
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
         Ok(())
     }
 
     #[test]
-    fn rgb_711() -> Result<()> {
-        let (mut tlc, array): (TLC59711<_, _>, BitVec) = test_configuration_random(1);
+    fn rgb_711() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC59711<_, _>, Register) = test_configuration_random(1);
 
         for (pos, val) in array.chunks(48).enumerate() {
             let val: Vec<u16> = val
                 .chunks(16)
-                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | bit as u16))
+                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
                 .collect();
             tlc.set_rgb(pos, (val[0], val[1], val[2]));
         }
         tlc.write()?;
 
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_all_711() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC59711<_, _>, Register) = test_configuration_random(1);
+
+        assert_eq!(tlc.channel_count(), 12);
+        assert_eq!(tlc.device_count(), 1);
+
+        let values: Vec<u16> = array
+            .chunks(16)
+            .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
+            .collect();
+        tlc.set_all(&values);
+        tlc.write()?;
+
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
         Ok(())
     }
 
     #[test]
-    fn chained_711_512() -> Result<()> {
-        let (mut tlc, array): (TLC59711<_, _>, BitVec) = test_configuration_random(512);
+    fn chained_711_512() -> Result<(), ErrorKind, MockError> {
+        let (mut tlc, array): (TLC59711<_, _>, Register) = test_configuration_random(512);
 
         for (pos, val) in array.chunks(48).enumerate() {
             let val: Vec<u16> = val
                 .chunks(16)
-                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | bit as u16))
+                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
                 .collect();
             tlc.set_rgb(pos, (val[0], val[1], val[2]));
         }
         tlc.write()?;
 
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
+        Ok(())
+    }
+
+    #[test]
+    fn function_control_and_brightness_711() -> Result<(), ErrorKind, MockError> {
+        // Header byte layout for command=0b100101, outtmg=0, extgck=1, tmgrst=0, dsprpt=1,
+        // blank=1, bc fields (in blue, green, red order) b=0x55, g=0x00, r=0x7F.
+        let mut frame = vec![0x95, 0x75, 0x40, 0x7f];
+        frame.resize(frame.len() + 16 * 12 / 8, 0);
+
+        let spi_expectation = [
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(frame),
+            SpiTransaction::transaction_end(),
+        ];
+        let pin_expectation: [PinTransaction; 2] = [
+            PinTransaction::set(PinState::High),
+            PinTransaction::set(PinState::Low),
+        ];
+
+        let spi = SpiMock::new(&spi_expectation);
+        let pin = PinMock::new(&pin_expectation);
+        let mut tlc = TLC59711::new(spi, pin, 1);
+
+        tlc.set_function_control(true, true, false, true, false);
+        tlc.set_brightness(0x7f, 0x00, 0x55);
+        tlc.write()?;
+
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn chained_711_512_async() -> Result<(), embedded_hal_async::spi::ErrorKind, MockError> {
+        let (mut tlc, array): (TLC59711<_, _>, Register) = test_configuration_random(512);
+
+        for (pos, val) in array.chunks(48).enumerate() {
+            let val: Vec<u16> = val
+                .chunks(16)
+                .map(|b| b.iter().fold(0, |acc, bit| (acc << 1) | *bit as u16))
+                .collect();
+            tlc.set_rgb(pos, (val[0], val[1], val[2]));
+        }
+        futures::executor::block_on(tlc.write_async())?;
+
+        let (mut spi, mut lat) = tlc.destroy();
+        spi.done();
+        lat.done();
+
         Ok(())
     }
 }